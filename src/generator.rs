@@ -1,5 +1,8 @@
 use crate::{constants, group_hash::find_group_hash, Point};
 use algebra::{biginteger::BigInteger256, PrimeField, TEModelParameters};
+use alloc::boxed::Box;
+use core::any::{Any, TypeId};
+use once_cell::race::OnceBox;
 
 /// Fixed generators of the Jubjub curve of unknown
 /// exponent.
@@ -32,9 +35,95 @@ pub enum FixedGenerators {
     SpendingKeyGenerator = 5,
 }
 
+/// The number of fixed generators.
+const NUM_GENERATORS: usize = 6;
+
+/// One node of the process-wide generator cache, holding the six derived points
+/// for a single curve parameter type `E`.
+///
+/// Deriving a generator runs an unbounded Blake2s trial loop plus a cofactor
+/// multiplication, so caching the decoded points keeps `sign`, `verify`,
+/// `from_private`, and the commitment paths from repeating that work. The cache
+/// is keyed per `E` (not by generator index alone): two curves instantiated in
+/// the same process must never share entries, or `point::<E2>` could hand back a
+/// point derived under `E1`.
+struct CurveCache {
+    /// Identity of the `E` this node caches, used to look it up again.
+    type_id: TypeId,
+    /// The six generators for this `E`, erased so differently-typed caches can
+    /// live in the same list. Always a `[OnceBox<Point<E>>; NUM_GENERATORS]`.
+    generators: Box<dyn Any + Send + Sync>,
+    /// The cache for the next `E` seen, appended lazily and race-free.
+    next: OnceBox<CurveCache>,
+}
+
+/// Head of an append-only, lock-free list of per-curve caches.
+static GENERATOR_CACHE: OnceBox<CurveCache> = OnceBox::new();
+
+impl CurveCache {
+    fn new<E>() -> Box<Self>
+    where
+        E: 'static,
+        Point<E>: Send + Sync,
+    {
+        let generators: [OnceBox<Point<E>>; NUM_GENERATORS] = [
+            OnceBox::new(),
+            OnceBox::new(),
+            OnceBox::new(),
+            OnceBox::new(),
+            OnceBox::new(),
+            OnceBox::new(),
+        ];
+        Box::new(CurveCache {
+            type_id: TypeId::of::<E>(),
+            generators: Box::new(generators),
+            next: OnceBox::new(),
+        })
+    }
+}
+
 impl FixedGenerators {
-    // TODO: cache value
     pub fn point<E>(&self) -> Point<E>
+    where
+        E: 'static + TEModelParameters,
+        E::BaseField: PrimeField + Into<BigInteger256>,
+        Point<E>: Send + Sync,
+    {
+        let type_id = TypeId::of::<E>();
+        let mut node = GENERATOR_CACHE.get_or_init(CurveCache::new::<E>);
+        while node.type_id != type_id {
+            node = node.next.get_or_init(CurveCache::new::<E>);
+        }
+
+        let generators = node
+            .generators
+            .downcast_ref::<[OnceBox<Point<E>>; NUM_GENERATORS]>()
+            .expect("cache node is keyed by E, so its generators have type E");
+
+        *generators[*self as usize].get_or_init(|| Box::new(self.derive::<E>()))
+    }
+
+    /// Forces derivation of all six generators, populating the cache up front.
+    pub fn prewarm<E>()
+    where
+        E: 'static + TEModelParameters,
+        E::BaseField: PrimeField + Into<BigInteger256>,
+        Point<E>: Send + Sync,
+    {
+        for g in &[
+            FixedGenerators::ProofGenerationKey,
+            FixedGenerators::NoteCommitmentRandomness,
+            FixedGenerators::NullifierPosition,
+            FixedGenerators::ValueCommitmentValue,
+            FixedGenerators::ValueCommitmentRandomness,
+            FixedGenerators::SpendingKeyGenerator,
+        ] {
+            let _ = g.point::<E>();
+        }
+    }
+
+    /// Freshly derives the generator via the group hash, bypassing the cache.
+    fn derive<E>(&self) -> Point<E>
     where
         E: TEModelParameters,
         E::BaseField: PrimeField + Into<BigInteger256>,
@@ -63,3 +152,39 @@ impl FixedGenerators {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::FixedGenerators;
+    use algebra::curves::jubjub::JubJubParameters;
+
+    #[test]
+    fn cached_point_matches_fresh_derivation() {
+        for g in &[
+            FixedGenerators::ProofGenerationKey,
+            FixedGenerators::NoteCommitmentRandomness,
+            FixedGenerators::NullifierPosition,
+            FixedGenerators::ValueCommitmentValue,
+            FixedGenerators::ValueCommitmentRandomness,
+            FixedGenerators::SpendingKeyGenerator,
+        ] {
+            let fresh = g.derive::<JubJubParameters>();
+            assert_eq!(g.point::<JubJubParameters>(), fresh);
+        }
+    }
+
+    #[test]
+    fn repeated_point_is_stable() {
+        FixedGenerators::prewarm::<JubJubParameters>();
+
+        // Repeated reads (as performed by repeated `verify` calls) return the
+        // same cached generator rather than re-running the group hash.
+        let first = FixedGenerators::SpendingKeyGenerator.point::<JubJubParameters>();
+        for _ in 0..16 {
+            assert_eq!(
+                FixedGenerators::SpendingKeyGenerator.point::<JubJubParameters>(),
+                first
+            );
+        }
+    }
+}
@@ -1,10 +1,14 @@
 #![no_std]
 
+extern crate alloc;
+
 mod constants;
 mod generator;
 mod group_hash;
+mod pedersen_hash;
 mod point;
 mod util;
+mod value_commitment;
 
 use algebra::{
     biginteger::BigInteger256,
@@ -19,10 +23,12 @@ use core::{
 };
 use point::mul_by_cofactor;
 use rand::Rng;
-use util::h_star;
+use util::{h_star, random_scalar};
 
 pub use generator::FixedGenerators;
-pub use point::{read_point, write_point, Point};
+pub use pedersen_hash::{pedersen_hash, Personalization};
+pub use point::{read_point, sum_points, write_point, Point};
+pub use value_commitment::ValueCommitment;
 
 pub struct PrivateKey<E: TEModelParameters> {
     pub field: E::ScalarField,
@@ -38,8 +44,9 @@ impl<E: TEModelParameters> fmt::Debug for PrivateKey<E> {
 
 impl<E> PrivateKey<E>
 where
-    E: TEModelParameters,
+    E: 'static + TEModelParameters,
     E::BaseField: PrimeField + Into<BigInteger256>,
+    Point<E>: Send + Sync,
 {
     pub fn sign<R: Rng>(&self, msg: &[u8], rng: &mut R, generator: FixedGenerators) -> Signature {
         // T = (l_H + 128) bits of randomness
@@ -66,6 +73,18 @@ where
 
         Signature { rbar, sbar }
     }
+
+    /// Derives a re-randomized signing key `rsk = sk + alpha`.
+    ///
+    /// A signature produced by the returned key verifies under the public key
+    /// obtained from [`PublicKey::randomize`] with the same `alpha`. This is the
+    /// per-spend authorization key of the Sapling `ar` flow, so `alpha` must be
+    /// freshly sampled for every spend.
+    pub fn randomize(&self, alpha: &E::ScalarField) -> PrivateKey<E> {
+        let mut field = self.field;
+        field.add_assign(alpha);
+        PrivateKey { field }
+    }
 }
 
 pub struct PublicKey<E: TEModelParameters> {
@@ -87,8 +106,9 @@ where
 
 impl<E> PublicKey<E>
 where
-    E: TEModelParameters,
+    E: 'static + TEModelParameters,
     E::BaseField: PrimeField + Into<BigInteger256>,
+    Point<E>: Send + Sync,
 {
     pub fn new(point: Point<E>) -> Self {
         PublicKey {
@@ -102,6 +122,77 @@ where
         }
     }
 
+    /// Derives a re-randomized verification key `rvk = vk + [alpha] P_G`.
+    ///
+    /// `generator` must be the [`FixedGenerators::SpendingKeyGenerator`] used to
+    /// derive this key, so that `rvk` matches the key produced by
+    /// [`PrivateKey::randomize`] with the same `alpha`.
+    pub fn randomize(&self, alpha: &E::ScalarField, generator: FixedGenerators) -> PublicKey<E> {
+        PublicKey {
+            point: generator.point::<E>().mul(alpha).add(&self.point),
+        }
+    }
+
+    /// Verifies a bundle of signatures with a single multi-scalar equation.
+    ///
+    /// This is the standard RedDSA random-linear-combination check: each entry
+    /// contributes a fresh 128-bit random weight `z_i` drawn from `rng`, and the
+    /// accumulated equation
+    ///
+    /// ```text
+    /// [h]( [-Σ z_i·S_i] P_G + Σ [z_i] R_i + Σ [z_i·c_i] vk_i )
+    /// ```
+    ///
+    /// is checked to be the identity. A malformed `R_i`/`S_i` is rejected before
+    /// it can poison the accumulator, and a single invalid signature makes the
+    /// whole batch fail except with probability ~2⁻¹²⁸ per entry.
+    pub fn batch_verify<R: Rng>(
+        items: &[(PublicKey<E>, &[u8], &Signature)],
+        rng: &mut R,
+        generator: FixedGenerators,
+    ) -> bool {
+        let mut acc = Point::<E>::zero();
+        let mut s_acc = E::ScalarField::zero();
+
+        for (pubkey, msg, sig) in items {
+            // R != invalid
+            let r = match read_point(&sig.rbar[..]) {
+                Some(r) => r,
+                None => return false,
+            };
+
+            // S < order(G)
+            let s = match E::ScalarField::read(&sig.sbar[..]) {
+                Ok(s) => s,
+                Err(_) => return false,
+            };
+
+            // c = H*(Rbar || M)
+            let c = h_star::<E>(&sig.rbar[..], msg);
+
+            // z <-$ {0, 1}^128
+            let z = random_scalar::<E, R>(rng);
+
+            // Σ z_i·S_i
+            let mut zs = z;
+            zs.mul_assign(&s);
+            s_acc.add_assign(&zs);
+
+            // Σ [z_i] R_i
+            acc = acc.add(&r.mul(&z));
+
+            // Σ [z_i·c_i] vk_i
+            let mut zc = z;
+            zc.mul_assign(&c);
+            acc = acc.add(&pubkey.point.mul(&zc));
+        }
+
+        // 0 = h_G(-Σ z_i·S_i . P_G + Σ [z_i] R_i + Σ [z_i·c_i] vk_i)
+        acc = acc.add(&generator.point::<E>().mul(&s_acc).neg());
+
+        mul_by_cofactor(&acc).is_zero()
+    }
+
     pub fn verify(&self, msg: &[u8], sig: &Signature, generator: FixedGenerators) -> bool {
         // c = H*(Rbar || M)
         let c = h_star::<E>(&sig.rbar[..], msg);
@@ -177,4 +268,64 @@ mod tests {
         let pubkey = PublicKey::from_private(&privkey, generator);
         assert!(pubkey.verify(msg1, &sig1, generator));
     }
+
+    #[test]
+    fn randomization_commutes_with_from_private() {
+        let mut rng = StepRng::new(0, 1);
+        let generator = FixedGenerators::SpendingKeyGenerator;
+        let privkey: PrivateKey<JubJubParameters> = PrivateKey { field: rng.gen() };
+        let alpha = rng.gen();
+
+        // PublicKey::from_private(sk.randomize(a)) == from_private(sk).randomize(a)
+        let lhs = PublicKey::from_private(&privkey.randomize(&alpha), generator);
+        let rhs = PublicKey::from_private(&privkey, generator).randomize(&alpha, generator);
+        assert!(lhs.point == rhs.point);
+    }
+
+    #[test]
+    fn sign_and_verify_under_randomized_key() {
+        let mut rng = StepRng::new(0, 1);
+        let generator = FixedGenerators::SpendingKeyGenerator;
+        let privkey: PrivateKey<JubJubParameters> = PrivateKey { field: rng.gen() };
+        let alpha = rng.gen();
+
+        let rsk = privkey.randomize(&alpha);
+        let rvk = PublicKey::from_private(&privkey, generator).randomize(&alpha, generator);
+
+        let msg = b"Foo bar";
+        let sig = rsk.sign(msg, &mut rng, generator);
+        assert!(rvk.verify(msg, &sig, generator));
+    }
+
+    #[test]
+    fn batch_verify_accepts_and_rejects() {
+        let mut rng = StepRng::new(0, 1);
+        let generator = FixedGenerators::SpendingKeyGenerator;
+
+        let msgs: [&[u8]; 3] = [b"one", b"two", b"three"];
+        let mut pubkeys = alloc::vec::Vec::new();
+        let mut sigs = alloc::vec::Vec::new();
+        for msg in msgs.iter() {
+            let sk: PrivateKey<JubJubParameters> = PrivateKey { field: rng.gen() };
+            sigs.push(sk.sign(msg, &mut rng, generator));
+            pubkeys.push(PublicKey::from_private(&sk, generator));
+        }
+
+        let mut build = || {
+            (0..3)
+                .map(|i| (PublicKey::new(pubkeys[i].point), msgs[i], &sigs[i]))
+                .collect::<alloc::vec::Vec<_>>()
+        };
+
+        assert!(PublicKey::batch_verify(&build(), &mut rng, generator));
+
+        // Flipping one byte of any sbar makes the batch fail.
+        sigs[1].sbar[0] ^= 0xff;
+        assert!(!PublicKey::batch_verify(&build(), &mut rng, generator));
+        sigs[1].sbar[0] ^= 0xff;
+
+        // ... as does flipping one byte of any rbar.
+        sigs[2].rbar[0] ^= 0xff;
+        assert!(!PublicKey::batch_verify(&build(), &mut rng, generator));
+    }
 }
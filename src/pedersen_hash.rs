@@ -0,0 +1,306 @@
+//! The Pedersen hash over the Jubjub curve, as used by Sapling note
+//! commitments and the note-commitment Merkle tree.
+
+use crate::{constants, group_hash::find_group_hash, Point};
+use algebra::{
+    biginteger::BigInteger256,
+    fields::Field,
+    prelude::{One, Zero},
+    PrimeField, TEModelParameters,
+};
+use core::ops::{AddAssign, Neg};
+
+/// The domain separator of a Pedersen hash, prepended to the bit stream.
+#[derive(Copy, Clone)]
+pub enum Personalization {
+    /// Hashing a note plaintext into a note commitment.
+    NoteCommitment,
+    /// Hashing a pair of nodes at the given depth of the commitment tree.
+    MerkleTree(usize),
+}
+
+impl Personalization {
+    /// The six leading bits that distinguish the hashing domains.
+    fn get_bits(&self) -> [bool; 6] {
+        match *self {
+            Personalization::NoteCommitment => [true; 6],
+            Personalization::MerkleTree(num) => {
+                assert!(num < 63);
+
+                let mut bits = [false; 6];
+                for (i, bit) in bits.iter_mut().enumerate() {
+                    *bit = (num >> i) & 1 == 1;
+                }
+                bits
+            }
+        }
+    }
+}
+
+/// The number of 3-bit chunks folded into a single segment generator.
+const CHUNKS_PER_SEGMENT: usize = 63;
+
+/// Computes the Pedersen hash of `bits` under `personalization`.
+///
+/// The bit stream is consumed in signed-digit chunks of three bits
+/// `(b0, b1, b2)`, each encoding `enc = (1 - 2·b2)·(1 + b0 + 2·b1)`, with a
+/// final partial chunk padded with zeros. Within a segment the j-th chunk is
+/// weighted by `2^{4·j}` and the digits are summed into `acc`; after at most 63
+/// chunks the segment is closed with its generator `G_s` and a fresh segment
+/// begins. The result is `Σ_s [acc_s] G_s`.
+pub fn pedersen_hash<E>(personalization: Personalization, bits: impl Iterator<Item = bool>) -> Point<E>
+where
+    E: TEModelParameters,
+    E::BaseField: PrimeField + Into<BigInteger256>,
+{
+    let prefix = personalization.get_bits();
+    let mut bits = prefix.iter().copied().chain(bits);
+
+    let mut result = Point::<E>::zero();
+    let mut segment = 0usize;
+
+    loop {
+        // acc = Σ_j enc_j · 2^{4·j}
+        let mut acc = E::ScalarField::zero();
+        let mut cur = E::ScalarField::one();
+        let mut chunks = 0;
+        let mut encountered_bits = false;
+
+        while let Some(b0) = bits.next() {
+            encountered_bits = true;
+            let b1 = bits.next().unwrap_or(false);
+            let b2 = bits.next().unwrap_or(false);
+
+            // enc · cur = (1 - 2·b2)·(1 + b0 + 2·b1) · cur
+            let mut tmp = cur;
+            if b0 {
+                tmp.add_assign(&cur);
+            }
+            let mut two_cur = cur;
+            two_cur.double_in_place();
+            if b1 {
+                tmp.add_assign(&two_cur);
+            }
+            if b2 {
+                tmp = tmp.neg();
+            }
+            acc.add_assign(&tmp);
+
+            chunks += 1;
+            if chunks == CHUNKS_PER_SEGMENT {
+                break;
+            }
+
+            // cur *= 2^4 for the next chunk
+            cur.double_in_place();
+            cur.double_in_place();
+            cur.double_in_place();
+            cur.double_in_place();
+        }
+
+        if !encountered_bits {
+            break;
+        }
+
+        let g = segment_generator::<E>(segment);
+        result = result.add(&g.mul(&acc));
+        segment += 1;
+    }
+
+    result
+}
+
+/// Derives the generator `G_s` of the `s`-th Pedersen segment by group-hashing
+/// the little-endian encoding of `s` under the Pedersen personalization.
+fn segment_generator<E>(segment: usize) -> Point<E>
+where
+    E: TEModelParameters,
+    E::BaseField: PrimeField + Into<BigInteger256>,
+{
+    let tag = (segment as u32).to_le_bytes();
+    find_group_hash(&tag, constants::PEDERSEN_HASH_GENERATORS_PERSONALIZATION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pedersen_hash, Personalization, CHUNKS_PER_SEGMENT};
+    use crate::{constants, group_hash::find_group_hash, write_point, Point};
+    use algebra::{
+        curves::jubjub::JubJubParameters,
+        prelude::{One, Zero},
+    };
+    use alloc::vec::Vec;
+    use core::ops::{AddAssign, Neg};
+
+    type Fr = <JubJubParameters as algebra::TEModelParameters>::ScalarField;
+
+    fn bits(bytes: &[u8]) -> impl Iterator<Item = bool> + '_ {
+        bytes
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+    }
+
+    /// Independently accumulates the signed-digit segment scalars `acc_s` from a
+    /// full bit stream (personalization prefix already prepended), mirroring the
+    /// Sapling specification so the production accumulation can be checked
+    /// against it.
+    fn segment_scalars(all_bits: &[bool]) -> Vec<Fr> {
+        let mut out = Vec::new();
+        let mut i = 0;
+        while i < all_bits.len() {
+            let mut acc = Fr::zero();
+            let mut cur = Fr::one();
+            for chunk in 0..CHUNKS_PER_SEGMENT {
+                if i >= all_bits.len() {
+                    break;
+                }
+                let b0 = all_bits[i];
+                let b1 = all_bits.get(i + 1).copied().unwrap_or(false);
+                let b2 = all_bits.get(i + 2).copied().unwrap_or(false);
+                i += 3;
+
+                // enc·cur = (1 - 2·b2)·(1 + b0 + 2·b1)·cur
+                let mut tmp = cur;
+                if b0 {
+                    tmp.add_assign(&cur);
+                }
+                let mut two_cur = cur;
+                two_cur.double_in_place();
+                if b1 {
+                    tmp.add_assign(&two_cur);
+                }
+                if b2 {
+                    tmp = tmp.neg();
+                }
+                acc.add_assign(&tmp);
+
+                if chunk + 1 < CHUNKS_PER_SEGMENT {
+                    for _ in 0..4 {
+                        cur.double_in_place();
+                    }
+                }
+            }
+            out.push(acc);
+        }
+        out
+    }
+
+    /// The `s`-th segment generator, re-derived here with an explicit
+    /// little-endian `u32` tag so the wire encoding of the tag is pinned
+    /// independently of `super::segment_generator`.
+    fn generator_le(segment: u32) -> Point<JubJubParameters> {
+        find_group_hash(
+            &segment.to_le_bytes(),
+            constants::PEDERSEN_HASH_GENERATORS_PERSONALIZATION,
+        )
+    }
+
+    /// The 32-byte Sapling-style compressed encoding of `point`.
+    fn encode(point: &Point<JubJubParameters>) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        write_point(point, &mut bytes[..]).expect("Jubjub points serialize to 32 bytes");
+        bytes
+    }
+
+    /// A small scalar as an `Fr`, negated when `neg` is set.
+    fn scalar(value: u64, neg: bool) -> Fr {
+        let f = Fr::from(value);
+        if neg {
+            f.neg()
+        } else {
+            f
+        }
+    }
+
+    #[test]
+    fn hash_is_deterministic() {
+        let a = pedersen_hash::<JubJubParameters>(Personalization::NoteCommitment, bits(b"abc"));
+        let b = pedersen_hash::<JubJubParameters>(Personalization::NoteCommitment, bits(b"abc"));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn personalization_separates_domains() {
+        let commitment =
+            pedersen_hash::<JubJubParameters>(Personalization::NoteCommitment, bits(b"abc"));
+        let tree = pedersen_hash::<JubJubParameters>(Personalization::MerkleTree(0), bits(b"abc"));
+        assert_ne!(commitment, tree);
+
+        // Distinct tree depths hash the same input to distinct points.
+        let d0 = pedersen_hash::<JubJubParameters>(Personalization::MerkleTree(0), bits(b"abc"));
+        let d1 = pedersen_hash::<JubJubParameters>(Personalization::MerkleTree(1), bits(b"abc"));
+        assert_ne!(d0, d1);
+    }
+
+    #[test]
+    fn hash_spans_multiple_segments() {
+        // More than 63 chunks forces a second segment generator to be used.
+        let input = [0xa5u8; 64];
+        let a = pedersen_hash::<JubJubParameters>(Personalization::NoteCommitment, bits(&input));
+        let b = pedersen_hash::<JubJubParameters>(Personalization::NoteCommitment, bits(&input));
+        assert_eq!(a, b);
+    }
+
+    // Known-answer tests pinning the Sapling wire encoding that `pedersen_hash`
+    // must reproduce for byte-compatibility with `zcash_primitives`: the 6-bit
+    // LSB-first personalization prefix, the LSB-first bit order, the signed-digit
+    // radix-2⁴ accumulation, and the little-endian `u32` segment tag. Each hash
+    // is compared against the point re-derived straight from `find_group_hash`
+    // with an independently computed scalar, so any drift in those choices
+    // breaks the test.
+
+    #[test]
+    fn note_commitment_matches_group_hash() {
+        // NoteCommitment's all-ones prefix fills exactly two chunks, each the
+        // signed digit (1 - 2)·(1 + 1 + 2) = -4, weighted 1 and 2⁴: acc = -68.
+        let acc = scalar(68, true);
+        let expected = generator_le(0).mul(&acc);
+
+        let hash = pedersen_hash::<JubJubParameters>(Personalization::NoteCommitment, bits(&[]));
+        assert_eq!(hash, expected);
+        // The compressed encoding — the form consumed by zcash — must agree too.
+        assert_eq!(encode(&hash), encode(&expected));
+    }
+
+    #[test]
+    fn merkle_tree_matches_group_hash() {
+        // MerkleTree(6) encodes 6 = 0b000110 LSB-first as the prefix bits
+        // [0,1,1,0,0,0], giving chunk digits -3 and +1 weighted 1 and 2⁴:
+        // acc = -3 + 16 = 13.
+        let acc = scalar(13, false);
+        let expected = generator_le(0).mul(&acc);
+
+        let hash = pedersen_hash::<JubJubParameters>(Personalization::MerkleTree(6), bits(&[]));
+        assert_eq!(hash, expected);
+        assert_eq!(encode(&hash), encode(&expected));
+    }
+
+    #[test]
+    fn second_segment_uses_little_endian_u32_tag() {
+        // 23 zero bytes plus the 6-bit prefix span 190 bits: a full first
+        // segment (63 chunks) and a single chunk in the second, so both segment
+        // generators participate and the segment-1 tag encoding is exercised.
+        let input = [0u8; 23];
+        let hash = pedersen_hash::<JubJubParameters>(Personalization::NoteCommitment, bits(&input));
+
+        let mut all_bits = Vec::new();
+        all_bits.extend_from_slice(&[true; 6]);
+        all_bits.extend(bits(&input));
+        let accs = segment_scalars(&all_bits);
+        assert_eq!(accs.len(), 2);
+
+        let expected = generator_le(0)
+            .mul(&accs[0])
+            .add(&generator_le(1).mul(&accs[1]));
+        assert_eq!(hash, expected);
+
+        // A big-endian segment tag would pick a different generator for segment
+        // 1, so the hash must not match that reconstruction.
+        let be_segment1 = find_group_hash(
+            &1u32.to_be_bytes(),
+            constants::PEDERSEN_HASH_GENERATORS_PERSONALIZATION,
+        );
+        let be_expected = generator_le(0).mul(&accs[0]).add(&be_segment1.mul(&accs[1]));
+        assert_ne!(hash, be_expected);
+    }
+}
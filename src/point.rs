@@ -58,6 +58,18 @@ pub fn mul_by_cofactor<E: TEModelParameters>(p: &Point<E>) -> Point<E> {
     double(&double(&double(p)))
 }
 
+/// Sums a slice of points, returning the identity for an empty slice.
+///
+/// Useful for balance checks such as `Σ inputs − Σ outputs` over value
+/// commitments.
+pub fn sum_points<E: TEModelParameters>(points: &[Point<E>]) -> Point<E> {
+    let mut acc = Point::<E>::zero();
+    for p in points {
+        acc = acc.add(p);
+    }
+    acc
+}
+
 fn get_for_y<E>(y: E::BaseField, sign: bool) -> Option<Point<E>>
 where
     E: TEModelParameters,
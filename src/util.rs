@@ -1,10 +1,12 @@
 use algebra::{
+    bytes::FromBytes,
     fields::{BitIterator, Field},
     prelude::{One, Zero},
     TEModelParameters,
 };
 use blake2_rfc::blake2b::Blake2b;
 use core::{mem, ops::AddAssign};
+use rand::Rng;
 
 fn hash_to_scalar<E>(persona: &[u8], a: &[u8], b: &[u8]) -> E::ScalarField
 where
@@ -50,6 +52,18 @@ where
     hash_to_scalar::<E>(b"Zcash_RedJubjubH", a, b)
 }
 
+/// Samples a uniform 128-bit scalar, used as the random weight of an entry in
+/// batch verification. 128 bits is always in range for the Jubjub scalar field.
+pub fn random_scalar<E, R>(rng: &mut R) -> E::ScalarField
+where
+    E: TEModelParameters,
+    R: Rng,
+{
+    let mut repr = [0u8; 32];
+    rng.fill_bytes(&mut repr[..16]);
+    E::ScalarField::read(&repr[..]).expect("128-bit value is always a valid scalar")
+}
+
 #[cfg(test)]
 mod tests {
     use super::h_star;
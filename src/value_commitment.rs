@@ -0,0 +1,78 @@
+//! Homomorphic value commitments used for the Sapling balance check.
+
+use crate::{FixedGenerators, Point};
+use algebra::{biginteger::BigInteger256, PrimeField, TEModelParameters};
+
+/// A Pedersen commitment `[value] G_v + [randomness] G_r` to a `u64` value.
+///
+/// The commitments are additively homomorphic, so a verifier can check that
+/// `Σ inputs − Σ outputs` opens to `value·G_v + rcv·G_r` without learning the
+/// individual values.
+pub struct ValueCommitment<E: TEModelParameters> {
+    pub value: u64,
+    pub randomness: E::ScalarField,
+}
+
+impl<E> ValueCommitment<E>
+where
+    E: 'static + TEModelParameters,
+    E::BaseField: PrimeField + Into<BigInteger256>,
+    Point<E>: Send + Sync,
+{
+    /// Computes `cm = [value] G_v + [randomness] G_r`.
+    pub fn commitment(&self) -> Point<E> {
+        let value = FixedGenerators::ValueCommitmentValue
+            .point::<E>()
+            .mul(&E::ScalarField::from(self.value));
+        let randomness = FixedGenerators::ValueCommitmentRandomness
+            .point::<E>()
+            .mul(&self.randomness);
+
+        value.add(&randomness)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueCommitment;
+    use crate::point::sum_points;
+    use algebra::curves::jubjub::JubJubParameters;
+    use core::ops::{AddAssign, Neg, SubAssign};
+    use rand::{rngs::mock::StepRng, Rng};
+
+    #[test]
+    fn balance_check_is_homomorphic() {
+        let mut rng = StepRng::new(0, 1);
+
+        // Two inputs whose values balance one output.
+        let inputs = [
+            ValueCommitment::<JubJubParameters> {
+                value: 7,
+                randomness: rng.gen(),
+            },
+            ValueCommitment::<JubJubParameters> {
+                value: 5,
+                randomness: rng.gen(),
+            },
+        ];
+        let output = ValueCommitment::<JubJubParameters> {
+            value: 12,
+            randomness: rng.gen(),
+        };
+
+        // Σ inputs − Σ outputs opens to (0)·G_v + rcv·G_r.
+        let lhs = sum_points(&[inputs[0].commitment(), inputs[1].commitment()])
+            .add(&output.commitment().neg());
+
+        let mut rcv = inputs[0].randomness;
+        rcv.add_assign(&inputs[1].randomness);
+        rcv.sub_assign(&output.randomness);
+        let rhs = ValueCommitment::<JubJubParameters> {
+            value: 0,
+            randomness: rcv,
+        }
+        .commitment();
+
+        assert!(lhs == rhs);
+    }
+}